@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use std::time::UNIX_EPOCH;
@@ -15,6 +16,25 @@ pub struct DirEntry {
     mtime_ms: u64,
 }
 
+#[derive(Serialize)]
+pub struct BatchItemResult {
+    path: String,
+    error: Option<String>,
+}
+
+impl BatchItemResult {
+    fn ok(path: String) -> Self {
+        BatchItemResult { path, error: None }
+    }
+
+    fn err(path: String, error: String) -> Self {
+        BatchItemResult {
+            path,
+            error: Some(error),
+        }
+    }
+}
+
 #[derive(Serialize)]
 pub struct EntryMetadata {
     dirname: String,
@@ -24,6 +44,9 @@ pub struct EntryMetadata {
     date: String,
     tags: Vec<String>,
     excerpt: String,
+    /// Frontmatter fields beyond the core ones above (mood, location,
+    /// weather, custom taxonomies, ...), passed through as-is.
+    extra: HashMap<String, serde_json::Value>,
 }
 
 #[command]
@@ -85,6 +108,52 @@ pub async fn copy_file(source: String, destination: String) -> Result<(), String
     Ok(())
 }
 
+#[command]
+pub async fn copy_files(sources: Vec<(String, String)>) -> Result<Vec<BatchItemResult>, String> {
+    let mut results = Vec::with_capacity(sources.len());
+    for (source, destination) in sources {
+        let result = (|| -> Result<(), String> {
+            if let Some(parent) = Path::new(&destination).parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("Failed to create directory: {}", e))?;
+            }
+            fs::copy(&source, &destination).map_err(|e| format!("Failed to copy file: {}", e))?;
+            Ok(())
+        })();
+        results.push(match result {
+            Ok(()) => BatchItemResult::ok(destination),
+            Err(e) => BatchItemResult::err(destination, e),
+        });
+    }
+    Ok(results)
+}
+
+#[command]
+pub async fn move_paths(paths: Vec<(String, String)>) -> Result<Vec<BatchItemResult>, String> {
+    let mut results = Vec::with_capacity(paths.len());
+    for (old_path, new_path) in paths {
+        let result = fs::rename(&old_path, &new_path).map_err(|e| format!("Failed to rename: {}", e));
+        results.push(match result {
+            Ok(()) => BatchItemResult::ok(new_path),
+            Err(e) => BatchItemResult::err(new_path, e),
+        });
+    }
+    Ok(results)
+}
+
+#[command]
+pub async fn delete_directories(paths: Vec<String>) -> Result<Vec<BatchItemResult>, String> {
+    let mut results = Vec::with_capacity(paths.len());
+    for path in paths {
+        let result = fs::remove_dir_all(&path).map_err(|e| format!("Failed to delete directory: {}", e));
+        results.push(match result {
+            Ok(()) => BatchItemResult::ok(path),
+            Err(e) => BatchItemResult::err(path, e),
+        });
+    }
+    Ok(results)
+}
+
 #[command]
 pub async fn write_file_base64(path: String, base64_data: String) -> Result<(), String> {
     let data = if let Some(pos) = base64_data.find(',') {
@@ -151,17 +220,17 @@ pub async fn list_entries_with_metadata(path: String) -> Result<Vec<EntryMetadat
             .map(|d| d.as_millis() as u64)
             .unwrap_or(0);
 
-        // Parse YAML frontmatter
-        let (title, date, tags, excerpt) = parse_frontmatter(&content);
+        let parsed = parse_frontmatter(&content);
 
         entries.push(EntryMetadata {
             dirname,
             path: index_path.to_string_lossy().to_string(),
             mtime_ms,
-            title,
-            date,
-            tags,
-            excerpt,
+            title: parsed.title,
+            date: parsed.date,
+            tags: parsed.tags,
+            excerpt: parsed.excerpt,
+            extra: parsed.extra,
         });
     }
 
@@ -170,45 +239,101 @@ pub async fn list_entries_with_metadata(path: String) -> Result<Vec<EntryMetadat
     Ok(entries)
 }
 
-fn parse_frontmatter(content: &str) -> (String, String, Vec<String>, String) {
-    let mut title = String::new();
-    let mut date = String::new();
-    let mut tags: Vec<String> = Vec::new();
-    let mut body = content;
+pub(crate) struct ParsedFrontmatter {
+    pub title: String,
+    pub date: String,
+    pub tags: Vec<String>,
+    pub excerpt: String,
+    pub extra: HashMap<String, serde_json::Value>,
+}
 
+/// Split `content` into its YAML frontmatter block (if any) and the
+/// remaining body.
+fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
     if content.starts_with("---") {
         if let Some(end) = content[3..].find("\n---") {
-            let yaml_block = &content[4..3 + end];
-            body = content[3 + end + 4..].trim_start();
-
-            for line in yaml_block.lines() {
-                let line = line.trim();
-                if let Some(pos) = line.find(':') {
-                    let key = line[..pos].trim();
-                    let val = line[pos + 1..].trim();
-                    match key {
-                        "title" => {
-                            title = strip_quotes(val).to_string();
-                        }
-                        "date" => {
-                            date = strip_quotes(val).to_string();
-                        }
-                        "tags" => {
-                            if val.starts_with('[') && val.ends_with(']') {
-                                tags = val[1..val.len() - 1]
-                                    .split(',')
-                                    .map(|t| strip_quotes(t.trim()).to_string())
-                                    .filter(|t| !t.is_empty())
-                                    .collect();
-                            }
-                        }
-                        _ => {}
-                    }
+            // `block_end` is the index of the '\n' before the closing
+            // "---". For an empty block ("---\n---\n...") this is 3, which
+            // is before the opening delimiter's end (4) — `get` (rather
+            // than indexing) handles that without panicking.
+            let block_end = 3 + end;
+            let yaml_block = content.get(4..block_end).unwrap_or("");
+            let body = content[block_end + 4..].trim_start();
+            return (Some(yaml_block), body);
+        }
+    }
+    (None, content)
+}
+
+/// Coerce a scalar YAML value to a string the way a human author typing
+/// `date: 2024` or `title: 42` (parsed as numbers) would expect.
+fn scalar_to_string(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Accept `tags` as a sequence (inline `[a, b]` or block-style), a bare
+/// string, or mixed scalar types (`tags: [2024, personal]`) rather than
+/// erroring the whole document the way a strict `Vec<String>` would.
+fn coerce_tags(value: &serde_yaml::Value) -> Vec<String> {
+    match value {
+        serde_yaml::Value::Sequence(items) => items.iter().filter_map(scalar_to_string).collect(),
+        serde_yaml::Value::String(s) => vec![s.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Pull `title`/`date`/`tags` out of the frontmatter mapping field-by-field,
+/// coercing loosely-typed values instead of erroring, and collect everything
+/// else into `extra` untouched. A malformed `tags` or `date` entry should
+/// never cost the rest of the document its metadata.
+#[allow(clippy::type_complexity)]
+fn parse_frontmatter_fields(
+    yaml: &str,
+) -> (
+    Option<String>,
+    Option<String>,
+    Vec<String>,
+    HashMap<String, serde_json::Value>,
+) {
+    let Ok(serde_yaml::Value::Mapping(mapping)) = serde_yaml::from_str::<serde_yaml::Value>(yaml)
+    else {
+        return (None, None, Vec::new(), HashMap::new());
+    };
+
+    let mut title = None;
+    let mut date = None;
+    let mut tags = Vec::new();
+    let mut extra = HashMap::new();
+
+    for (key, value) in mapping {
+        let Some(key) = key.as_str() else { continue };
+        match key {
+            "title" => title = scalar_to_string(&value),
+            "date" => date = scalar_to_string(&value),
+            "tags" => tags = coerce_tags(&value),
+            _ => {
+                if let Ok(json_value) = serde_json::to_value(&value) {
+                    extra.insert(key.to_string(), json_value);
                 }
             }
         }
     }
 
+    (title, date, tags, extra)
+}
+
+pub(crate) fn parse_frontmatter(content: &str) -> ParsedFrontmatter {
+    let (yaml_block, body) = split_frontmatter(content);
+
+    let (title, date, tags, extra) = yaml_block
+        .map(parse_frontmatter_fields)
+        .unwrap_or_default();
+
     // Excerpt: first 300 chars of body (skip markdown heading on first line)
     let excerpt_src = if body.starts_with('#') {
         body.find('\n').map(|i| &body[i + 1..]).unwrap_or("")
@@ -217,13 +342,18 @@ fn parse_frontmatter(content: &str) -> (String, String, Vec<String>, String) {
     };
     let excerpt: String = excerpt_src.chars().take(300).collect();
 
-    (title, date, tags, excerpt.trim().to_string())
+    ParsedFrontmatter {
+        title: title.unwrap_or_default(),
+        date: date.unwrap_or_default(),
+        tags,
+        excerpt: excerpt.trim().to_string(),
+        extra,
+    }
 }
 
-fn strip_quotes(s: &str) -> &str {
-    if (s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\'')) {
-        &s[1..s.len() - 1]
-    } else {
-        s
-    }
+/// Return the entry body with any YAML frontmatter block stripped, for
+/// callers (e.g. the search indexer) that need the full text rather than
+/// the truncated excerpt `parse_frontmatter` produces.
+pub(crate) fn body_text(content: &str) -> &str {
+    split_frontmatter(content).1
 }
@@ -0,0 +1,4 @@
+pub mod dialog;
+pub mod entry;
+pub mod file;
+pub mod settings;
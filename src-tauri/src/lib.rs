@@ -9,6 +9,7 @@ pub fn run() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_shell::init())
+        .manage(services::watcher::WatcherState::new())
         .invoke_handler(tauri::generate_handler![
             commands::settings::get_user_data_path,
             commands::file::read_file,
@@ -17,13 +18,22 @@ pub fn run() {
             commands::entry::get_default_entries_dir,
             commands::entry::list_directory,
             commands::entry::delete_directory,
+            commands::entry::delete_directories,
             commands::entry::rename_path,
             commands::entry::copy_file,
+            commands::entry::copy_files,
+            commands::entry::move_paths,
             commands::entry::write_file_base64,
             commands::entry::list_entries_with_metadata,
             commands::dialog::open_file_dialog,
             commands::dialog::save_file_dialog,
             commands::dialog::choose_directory,
+            services::watcher::watch_entries_dir,
+            services::watcher::unwatch_entries_dir,
+            services::search::search_entries,
+            services::thumbnail::generate_thumbnail,
+            services::trash::trash_path,
+            services::trash::restore_trashed,
         ])
         .setup(|app| {
             log::info!("Lifespeed starting up...");
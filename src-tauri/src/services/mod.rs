@@ -0,0 +1,5 @@
+pub mod path_service;
+pub mod search;
+pub mod thumbnail;
+pub mod trash;
+pub mod watcher;
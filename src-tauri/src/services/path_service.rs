@@ -0,0 +1,22 @@
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use tauri::{AppHandle, Manager};
+
+static USER_DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Resolve and cache the app's user data directory. Must be called once
+/// during app setup before any command touches `get_user_data_dir`.
+pub fn init(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
+    let dir = app.path().app_data_dir()?;
+    std::fs::create_dir_all(&dir)?;
+    let _ = USER_DATA_DIR.set(dir);
+    Ok(())
+}
+
+pub fn get_user_data_dir() -> Result<PathBuf, String> {
+    USER_DATA_DIR
+        .get()
+        .cloned()
+        .ok_or_else(|| "User data directory not initialized".to_string())
+}
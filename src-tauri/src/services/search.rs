@@ -0,0 +1,305 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use tauri::command;
+
+use crate::commands::entry::{body_text, parse_frontmatter};
+use crate::services::path_service;
+
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+struct DocumentInfo {
+    mtime_ms: u64,
+    token_count: usize,
+    title: String,
+    date: String,
+    tags: Vec<String>,
+    excerpt: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Posting {
+    dirname: String,
+    term_frequency: u32,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct SearchIndex {
+    documents: HashMap<String, DocumentInfo>,
+    postings: HashMap<String, Vec<Posting>>,
+}
+
+#[derive(Serialize)]
+pub struct SearchResult {
+    dirname: String,
+    path: String,
+    mtime_ms: u64,
+    title: String,
+    date: String,
+    tags: Vec<String>,
+    snippet: String,
+    score: f64,
+}
+
+fn index_path() -> Result<PathBuf, String> {
+    let data_dir = path_service::get_user_data_dir()?;
+    Ok(data_dir.join("search_index.json"))
+}
+
+fn load_index() -> SearchIndex {
+    index_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_index(index: &SearchIndex) -> Result<(), String> {
+    let path = index_path()?;
+    let json = serde_json::to_string(index)
+        .map_err(|e| format!("Failed to serialize search index: {}", e))?;
+    fs::write(path, json).map_err(|e| format!("Failed to write search index: {}", e))
+}
+
+const SNIPPET_WINDOW: usize = 160;
+const SNIPPET_LEAD: usize = 40;
+
+/// Build a snippet around the first matching query term in `body`, falling
+/// back to `fallback` (the entry's stored excerpt) when none of the terms
+/// appear in the body (e.g. the entry matched on its title or tags only).
+fn build_snippet(body: &str, query_terms: &[String], fallback: &str) -> String {
+    let lower = body.to_lowercase();
+    let Some(match_pos) = query_terms
+        .iter()
+        .filter_map(|term| lower.find(term.as_str()))
+        .min()
+    else {
+        return fallback.to_string();
+    };
+
+    let start = lower[..match_pos]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_LEAD)
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    let end_target = match_pos + SNIPPET_WINDOW;
+    let end = body
+        .char_indices()
+        .map(|(i, _)| i)
+        .find(|&i| i >= end_target)
+        .unwrap_or(body.len());
+
+    let mut snippet = body[start..end].trim().to_string();
+    if start > 0 {
+        snippet = format!("...{}", snippet);
+    }
+    if end < body.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+fn remove_document(index: &mut SearchIndex, dirname: &str) {
+    if index.documents.remove(dirname).is_none() {
+        return;
+    }
+    for postings in index.postings.values_mut() {
+        postings.retain(|p| p.dirname != dirname);
+    }
+    index.postings.retain(|_, postings| !postings.is_empty());
+}
+
+fn index_document(index: &mut SearchIndex, dirname: &str, content: &str, mtime_ms: u64) {
+    let parsed = parse_frontmatter(content);
+    let searchable = format!(
+        "{} {} {}",
+        parsed.title,
+        parsed.tags.join(" "),
+        body_text(content)
+    );
+    let tokens = tokenize(&searchable);
+
+    let mut term_frequencies: HashMap<String, u32> = HashMap::new();
+    for token in &tokens {
+        *term_frequencies.entry(token.clone()).or_insert(0) += 1;
+    }
+    for (term, term_frequency) in term_frequencies {
+        index.postings.entry(term).or_default().push(Posting {
+            dirname: dirname.to_string(),
+            term_frequency,
+        });
+    }
+
+    index.documents.insert(
+        dirname.to_string(),
+        DocumentInfo {
+            mtime_ms,
+            token_count: tokens.len(),
+            title: parsed.title,
+            date: parsed.date,
+            tags: parsed.tags,
+            excerpt: parsed.excerpt,
+        },
+    );
+}
+
+fn file_mtime_ms(path: &Path) -> Option<u64> {
+    fs::metadata(path)
+        .ok()?
+        .modified()
+        .ok()?
+        .duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_millis() as u64)
+}
+
+/// Bring the index up to date with what's on disk: re-index any entry whose
+/// `index.md` mtime has changed since it was last indexed, add new entries,
+/// and drop ones that no longer exist. Returns whether the index changed.
+fn sync_index(index: &mut SearchIndex, entries_dir: &Path) -> Result<bool, String> {
+    if !entries_dir.exists() {
+        return Ok(false);
+    }
+
+    let mut changed = false;
+    let mut seen = std::collections::HashSet::new();
+    let read_dir =
+        fs::read_dir(entries_dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+
+    for item in read_dir {
+        let Ok(item) = item else { continue };
+        let Ok(metadata) = item.metadata() else {
+            continue;
+        };
+        if !metadata.is_dir() {
+            continue;
+        }
+
+        let dirname = item.file_name().to_string_lossy().to_string();
+        let index_md = item.path().join("index.md");
+        let Some(mtime_ms) = file_mtime_ms(&index_md) else {
+            continue;
+        };
+        seen.insert(dirname.clone());
+
+        let up_to_date = index
+            .documents
+            .get(&dirname)
+            .is_some_and(|doc| doc.mtime_ms == mtime_ms);
+        if up_to_date {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(&index_md) else {
+            continue;
+        };
+        remove_document(index, &dirname);
+        index_document(index, &dirname, &content, mtime_ms);
+        changed = true;
+    }
+
+    let stale: Vec<String> = index
+        .documents
+        .keys()
+        .filter(|dirname| !seen.contains(*dirname))
+        .cloned()
+        .collect();
+    if !stale.is_empty() {
+        changed = true;
+    }
+    for dirname in stale {
+        remove_document(index, &dirname);
+    }
+
+    Ok(changed)
+}
+
+/// Search journal entries by title/tags/body text, ranked with BM25.
+#[command]
+pub async fn search_entries(
+    path: String,
+    query: String,
+    limit: usize,
+) -> Result<Vec<SearchResult>, String> {
+    let entries_dir = Path::new(&path);
+    let mut index = load_index();
+    if sync_index(&mut index, entries_dir)? {
+        save_index(&index)?;
+    }
+
+    let query_terms = tokenize(&query);
+    if query_terms.is_empty() || index.documents.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let doc_count = index.documents.len() as f64;
+    let avg_doc_len = index
+        .documents
+        .values()
+        .map(|d| d.token_count as f64)
+        .sum::<f64>()
+        / doc_count;
+
+    let mut scores: HashMap<String, f64> = HashMap::new();
+    for term in &query_terms {
+        let Some(postings) = index.postings.get(term) else {
+            continue;
+        };
+        let df = postings.len() as f64;
+        let idf = ((doc_count - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+        for posting in postings {
+            let Some(doc) = index.documents.get(&posting.dirname) else {
+                continue;
+            };
+            let tf = posting.term_frequency as f64;
+            let doc_len = doc.token_count as f64;
+            let denom = tf + K1 * (1.0 - B + B * doc_len / avg_doc_len.max(1.0));
+            *scores.entry(posting.dirname.clone()).or_insert(0.0) += idf * tf * (K1 + 1.0) / denom;
+        }
+    }
+
+    let mut ranked: Vec<(String, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(limit);
+
+    Ok(ranked
+        .into_iter()
+        .filter_map(|(dirname, score)| {
+            let doc = index.documents.get(&dirname)?;
+            let index_md = entries_dir.join(&dirname).join("index.md");
+
+            // Only the (limit-bounded) results we're actually returning pay
+            // for a re-read, so the snippet can be windowed around the
+            // query match instead of always being the generic excerpt.
+            let snippet = fs::read_to_string(&index_md)
+                .ok()
+                .map(|content| build_snippet(body_text(&content), &query_terms, &doc.excerpt))
+                .unwrap_or_else(|| doc.excerpt.clone());
+
+            Some(SearchResult {
+                path: index_md.to_string_lossy().to_string(),
+                dirname,
+                mtime_ms: doc.mtime_ms,
+                title: doc.title.clone(),
+                date: doc.date.clone(),
+                tags: doc.tags.clone(),
+                snippet,
+                score,
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,68 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use image::imageops::FilterType;
+use tauri::command;
+
+use crate::services::path_service;
+
+fn thumbnail_cache_dir() -> Result<PathBuf, String> {
+    let data_dir = path_service::get_user_data_dir()?;
+    let dir = data_dir.join("thumbnails");
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create thumbnail cache directory: {}", e))?;
+    Ok(dir)
+}
+
+fn cache_key(source_path: &Path, mtime_ms: u64, max_edge: u32) -> String {
+    let mut hasher = DefaultHasher::new();
+    source_path.hash(&mut hasher);
+    mtime_ms.hash(&mut hasher);
+    max_edge.hash(&mut hasher);
+    format!("{:016x}.thumb.webp", hasher.finish())
+}
+
+fn source_mtime_ms(source_path: &Path) -> Result<u64, String> {
+    std::fs::metadata(source_path)
+        .map_err(|e| format!("Failed to read source metadata: {}", e))?
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as u64)
+        .ok_or_else(|| "Failed to read source modification time".to_string())
+}
+
+/// Decode `source_path`, resize so its longest edge is at most `max_edge`
+/// (preserving aspect ratio), and cache the result as a `.thumb.webp` file
+/// under the thumbnail cache dir, keyed by source path + mtime + max_edge.
+/// Returns the cache path, regenerating only when the source has changed.
+#[command]
+pub async fn generate_thumbnail(source_path: String, max_edge: u32) -> Result<String, String> {
+    let source = Path::new(&source_path);
+    let mtime_ms = source_mtime_ms(source)?;
+
+    let cache_dir = thumbnail_cache_dir()?;
+    let cache_path = cache_dir.join(cache_key(source, mtime_ms, max_edge));
+
+    if cache_path.exists() {
+        return Ok(cache_path.to_string_lossy().to_string());
+    }
+
+    let image = image::open(source).map_err(|e| format!("Failed to decode image: {}", e))?;
+    let (width, height) = (image.width(), image.height());
+    let longest_edge = width.max(height);
+
+    let resized = if longest_edge > max_edge {
+        image.resize(max_edge, max_edge, FilterType::Lanczos3)
+    } else {
+        image
+    };
+
+    resized
+        .save_with_format(&cache_path, image::ImageFormat::WebP)
+        .map_err(|e| format!("Failed to write thumbnail: {}", e))?;
+
+    Ok(cache_path.to_string_lossy().to_string())
+}
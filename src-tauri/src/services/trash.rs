@@ -0,0 +1,35 @@
+use tauri::command;
+
+/// Move `path` to the OS recycle bin/Trash instead of deleting it
+/// permanently. Prefer this over `delete_directory` for user-initiated
+/// deletes so they stay recoverable through the OS.
+#[command]
+pub async fn trash_path(path: String) -> Result<(), String> {
+    trash::delete(&path).map_err(|e| format!("Failed to move to trash: {}", e))
+}
+
+/// Restore a previously trashed entry, where the platform supports it.
+/// `trash::os_limited` (listing/restoring specific items) isn't available
+/// on macOS/iOS, so restore is unsupported there.
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+#[command]
+pub async fn restore_trashed(path: String) -> Result<(), String> {
+    let items = trash::os_limited::list()
+        .map_err(|e| format!("Failed to list trash: {}", e))?
+        .into_iter()
+        .filter(|item| item.original_path() == std::path::Path::new(&path))
+        .collect::<Vec<_>>();
+
+    let Some(item) = items.into_iter().next() else {
+        return Err(format!("{} was not found in the trash", path));
+    };
+
+    trash::os_limited::restore_all([item])
+        .map_err(|e| format!("Failed to restore from trash: {}", e))
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+#[command]
+pub async fn restore_trashed(_path: String) -> Result<(), String> {
+    Err("Restoring from trash is not supported on this platform".to_string())
+}
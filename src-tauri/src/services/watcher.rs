@@ -0,0 +1,148 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::{command, AppHandle, Emitter};
+
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Holds the active filesystem watchers, keyed by the directory they watch.
+pub struct WatcherState(Mutex<HashMap<String, RecommendedWatcher>>);
+
+impl WatcherState {
+    pub fn new() -> Self {
+        WatcherState(Mutex::new(HashMap::new()))
+    }
+}
+
+impl Default for WatcherState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+enum ChangeKind {
+    Created,
+    Modified,
+    Removed,
+    Renamed,
+}
+
+#[derive(Serialize, Clone)]
+struct ChangedEntry {
+    dirname: String,
+    kind: ChangeKind,
+}
+
+#[derive(Serialize, Clone)]
+struct EntriesChangedPayload {
+    dir: String,
+    entries: Vec<ChangedEntry>,
+}
+
+/// Start watching `path` for changes to its immediate subdirectories and
+/// debounce raw FS events into `entries-changed` events on the frontend.
+#[command]
+pub async fn watch_entries_dir(
+    app: AppHandle,
+    state: tauri::State<'_, WatcherState>,
+    path: String,
+) -> Result<(), String> {
+    let mut watchers = state.0.lock().map_err(|_| "Watcher state poisoned".to_string())?;
+    if watchers.contains_key(&path) {
+        return Ok(());
+    }
+
+    let (tx, rx) = channel::<notify::Event>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    })
+    .map_err(|e| format!("Failed to create watcher: {}", e))?;
+
+    watcher
+        .watch(Path::new(&path), RecursiveMode::Recursive)
+        .map_err(|e| format!("Failed to watch directory: {}", e))?;
+
+    watchers.insert(path.clone(), watcher);
+    drop(watchers);
+
+    let watch_root = PathBuf::from(&path);
+    std::thread::spawn(move || run_debounce_loop(app, watch_root, rx));
+
+    Ok(())
+}
+
+#[command]
+pub async fn unwatch_entries_dir(
+    state: tauri::State<'_, WatcherState>,
+    path: String,
+) -> Result<(), String> {
+    let mut watchers = state.0.lock().map_err(|_| "Watcher state poisoned".to_string())?;
+    watchers.remove(&path);
+    Ok(())
+}
+
+fn run_debounce_loop(
+    app: AppHandle,
+    watch_root: PathBuf,
+    rx: std::sync::mpsc::Receiver<notify::Event>,
+) {
+    let mut pending: HashMap<String, ChangeKind> = HashMap::new();
+
+    loop {
+        match rx.recv_timeout(DEBOUNCE) {
+            Ok(event) => {
+                for dirname in entry_dirnames(&watch_root, &event) {
+                    pending.insert(dirname, classify(&event));
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if !pending.is_empty() {
+                    let entries = pending
+                        .drain()
+                        .map(|(dirname, kind)| ChangedEntry { dirname, kind })
+                        .collect();
+                    let _ = app.emit(
+                        "entries-changed",
+                        EntriesChangedPayload {
+                            dir: watch_root.to_string_lossy().to_string(),
+                            entries,
+                        },
+                    );
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+}
+
+fn classify(event: &notify::Event) -> ChangeKind {
+    use notify::EventKind;
+    match event.kind {
+        EventKind::Create(_) => ChangeKind::Created,
+        EventKind::Remove(_) => ChangeKind::Removed,
+        EventKind::Modify(notify::event::ModifyKind::Name(_)) => ChangeKind::Renamed,
+        EventKind::Modify(_) => ChangeKind::Modified,
+        _ => ChangeKind::Modified,
+    }
+}
+
+/// Map the raw paths in an event to the affected entry directory names
+/// (the immediate child of `watch_root` each path falls under).
+fn entry_dirnames(watch_root: &Path, event: &notify::Event) -> HashSet<String> {
+    event
+        .paths
+        .iter()
+        .filter_map(|p| p.strip_prefix(watch_root).ok())
+        .filter_map(|rel| rel.components().next())
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect()
+}